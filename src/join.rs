@@ -0,0 +1,361 @@
+//! The join module provides the [`Joinable`] trait and the [`Join`] type it
+//! produces, which lazily `Display`s the elements of an iterable, separated
+//! by a separator.
+
+use core::fmt::{self, Display, Formatter};
+use std::io;
+use std::string::String;
+
+use crate::separators::Separator;
+
+/// The estimated number of bytes a single element contributes to a
+/// [`Join::join_to_string`] capacity reservation, used when no better
+/// information is available.
+const ELEMENT_SIZE_ESTIMATE: usize = 4;
+
+/// Trait for iterables that can be joined with a separator.
+///
+/// This trait is implemented for all `IntoIterator` types. See [`join_with`](Joinable::join_with)
+/// for more information.
+pub trait Joinable: IntoIterator + Sized {
+    /// Combine this iterable with a separator, to create a new `Join`
+    /// instance. Note that the separator doesn't have to be the same type
+    /// as the iterable's values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use joinery::Joinable;
+    ///
+    /// let parts = vec!["this", "is", "a", "sentence"];
+    /// let join = parts.join_with(" ");
+    ///
+    /// assert_eq!(join.to_string(), "this is a sentence");
+    /// ```
+    fn join_with<S>(self, sep: S) -> Join<Self::IntoIter, S> {
+        Join {
+            iter: self.into_iter(),
+            sep,
+        }
+    }
+
+    /// Combine this iterable with a separator, to create a new
+    /// `JoinTerminated` instance, which emits the separator after *every*
+    /// element, including the last. Joining an empty iterable emits
+    /// nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use joinery::{Joinable, Newline};
+    ///
+    /// let lines = vec!["first", "second", "third"];
+    /// let join = lines.join_with_terminator(Newline);
+    ///
+    /// assert_eq!(join.to_string(), "first\nsecond\nthird\n");
+    /// ```
+    fn join_with_terminator<S>(self, sep: S) -> JoinTerminated<Self::IntoIter, S> {
+        JoinTerminated {
+            iter: self.into_iter(),
+            sep,
+        }
+    }
+
+    /// Combine this iterable with a separator, to create a new
+    /// `JoinPrefixed` instance, which emits the separator before every
+    /// element, including the first. Joining an empty iterable emits
+    /// nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use joinery::Joinable;
+    ///
+    /// let parts = vec!["a", "b", "c"];
+    /// let join = parts.join_with_prefix("/");
+    ///
+    /// assert_eq!(join.to_string(), "/a/b/c");
+    /// ```
+    fn join_with_prefix<S>(self, sep: S) -> JoinPrefixed<Self::IntoIter, S> {
+        JoinPrefixed {
+            iter: self.into_iter(),
+            sep,
+        }
+    }
+}
+
+impl<T: IntoIterator> Joinable for T {}
+
+/// The result of calling [`join_with`](Joinable::join_with). This type
+/// implements `Display`, lazily writing the elements of the iterator
+/// separated by the separator, with no intermediate allocation.
+#[derive(Debug, Clone, Copy)]
+#[must_use]
+pub struct Join<I, S> {
+    iter: I,
+    sep: S,
+}
+
+/// Write the elements of `iter`, separated by `sep`, into `writer`. Used as
+/// the common core of `Display`, [`Join::write_to_fmt`], and
+/// [`Join::write_to_io`].
+fn write_elements<I, S, W>(mut iter: I, sep: &S, writer: &mut W) -> fmt::Result
+where
+    I: Iterator,
+    I::Item: Display,
+    S: Display,
+    W: fmt::Write,
+{
+    if let Some(first) = iter.next() {
+        write!(writer, "{}", first)?;
+
+        for element in iter {
+            write!(writer, "{}", sep)?;
+            write!(writer, "{}", element)?;
+        }
+    }
+
+    Ok(())
+}
+
+impl<I, S> Display for Join<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write_elements(self.iter.clone(), &self.sep, f)
+    }
+}
+
+impl<I, S> Join<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+{
+    /// Write this join directly into a [`fmt::Write`] sink, element by
+    /// element, with no intermediate `String` allocation.
+    ///
+    /// This is equivalent to [`Display::fmt`], but can be called with any
+    /// `fmt::Write` sink, not just a `Formatter`.
+    pub fn write_to_fmt<W: fmt::Write>(&self, writer: &mut W) -> fmt::Result {
+        write_elements(self.iter.clone(), &self.sep, writer)
+    }
+}
+
+impl<I, S> Join<I, S>
+where
+    I: Iterator + Clone + ExactSizeIterator,
+    I::Item: Display,
+    S: Separator,
+{
+    /// Join into an owned `String`, pre-reserving capacity when possible.
+    ///
+    /// Since the element iterator reports its length and the separator
+    /// reports [`len_hint`](Separator::len_hint), the required separator
+    /// capacity is known exactly; element capacity is a rough estimate, so
+    /// this may still reallocate for elements that render larger than
+    /// expected. This does not change the lazy, allocation-free behavior of
+    /// `Display` or [`write_to_fmt`](Join::write_to_fmt) for everyone else.
+    pub fn join_to_string(&self) -> String {
+        let len = self.iter.len();
+
+        let sep_capacity = self
+            .sep
+            .len_hint()
+            .map(|sep_len| len.saturating_sub(1) * sep_len)
+            .unwrap_or(0);
+        let capacity = sep_capacity + len * ELEMENT_SIZE_ESTIMATE;
+
+        let mut buf = String::with_capacity(capacity);
+        self.write_to_fmt(&mut buf)
+            .expect("writing to a String cannot fail");
+        buf
+    }
+}
+
+impl<I, S> Join<I, S>
+where
+    I: Iterator,
+    I::Item: Display,
+    S: Display,
+{
+    /// Write this join directly into an [`io::Write`] sink, element by
+    /// element, with no intermediate `String` allocation.
+    ///
+    /// Unlike `Display`, which panics on a formatting error, I/O errors
+    /// encountered while writing are propagated to the caller.
+    pub fn write_to_io<W: io::Write>(self, writer: W) -> io::Result<()> {
+        let mut adapter = IoWriteAdapter {
+            writer,
+            error: None,
+        };
+
+        match write_elements(self.iter, &self.sep, &mut adapter) {
+            Ok(()) => Ok(()),
+            Err(fmt::Error) => Err(adapter
+                .error
+                .expect("fmt::Write failed without IoWriteAdapter recording an io::Error")),
+        }
+    }
+}
+
+/// The result of calling [`join_with_terminator`](Joinable::join_with_terminator).
+/// Like [`Join`], this type lazily `Display`s the elements of the iterator,
+/// but emits the separator after every element, including the last.
+#[derive(Debug, Clone, Copy)]
+#[must_use]
+pub struct JoinTerminated<I, S> {
+    iter: I,
+    sep: S,
+}
+
+impl<I, S> Display for JoinTerminated<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for element in self.iter.clone() {
+            write!(f, "{}", element)?;
+            write!(f, "{}", self.sep)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The result of calling [`join_with_prefix`](Joinable::join_with_prefix).
+/// Like [`Join`], this type lazily `Display`s the elements of the iterator,
+/// but emits the separator before every element, including the first.
+#[derive(Debug, Clone, Copy)]
+#[must_use]
+pub struct JoinPrefixed<I, S> {
+    iter: I,
+    sep: S,
+}
+
+impl<I, S> Display for JoinPrefixed<I, S>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for element in self.iter.clone() {
+            write!(f, "{}", self.sep)?;
+            write!(f, "{}", element)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Adapts an [`io::Write`] sink so it can be written to via [`fmt::Write`],
+/// stashing any I/O error encountered so it can be recovered afterwards,
+/// rather than discarded as `fmt::Error` would otherwise force.
+struct IoWriteAdapter<W> {
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for IoWriteAdapter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.writer.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::join::Joinable;
+    use std::string::{String, ToString};
+    use std::vec::Vec;
+
+    #[test]
+    fn test_join_with() {
+        let data = [1, 2, 3, 4, 5];
+        let join = data.join_with(", ");
+
+        assert_eq!(join.to_string(), "1, 2, 3, 4, 5");
+    }
+
+    #[test]
+    fn test_write_to_fmt() {
+        let data = ["a", "b", "c"];
+        let join = data.join_with('-');
+
+        let mut buf = String::new();
+        join.write_to_fmt(&mut buf).unwrap();
+
+        assert_eq!(buf, "a-b-c");
+    }
+
+    #[test]
+    fn test_write_to_io() {
+        let data = ["a", "b", "c"];
+        let join = data.join_with('-');
+
+        let mut buf = Vec::new();
+        join.write_to_io(&mut buf).unwrap();
+
+        assert_eq!(buf, b"a-b-c");
+    }
+
+    #[test]
+    fn test_join_with_terminator() {
+        let data = [1, 2, 3];
+        let join = data.join_with_terminator(';');
+
+        assert_eq!(join.to_string(), "1;2;3;");
+    }
+
+    #[test]
+    fn test_join_with_terminator_empty() {
+        let data: [i32; 0] = [];
+        let join = data.join_with_terminator(';');
+
+        assert_eq!(join.to_string(), "");
+    }
+
+    #[test]
+    fn test_join_with_prefix() {
+        let data = [1, 2, 3];
+        let join = data.join_with_prefix(';');
+
+        assert_eq!(join.to_string(), ";1;2;3");
+    }
+
+    #[test]
+    fn test_join_with_prefix_empty() {
+        let data: [i32; 0] = [];
+        let join = data.join_with_prefix(';');
+
+        assert_eq!(join.to_string(), "");
+    }
+
+    #[test]
+    fn test_join_to_string() {
+        use crate::separators::CommaSpace;
+
+        let data = [1, 2, 3, 4, 5];
+        let join = data.join_with(CommaSpace);
+
+        assert_eq!(join.join_to_string(), "1, 2, 3, 4, 5");
+    }
+
+    #[test]
+    fn test_join_to_string_empty() {
+        use crate::separators::Comma;
+
+        let data: [i32; 0] = [];
+        let join = data.join_with(Comma);
+
+        assert_eq!(join.join_to_string(), "");
+    }
+}
@@ -0,0 +1,26 @@
+//! `joinery` provides generic and efficient joining of iterable state for
+//! Rust's `std::fmt` and `std::iter` systems. It provides the `Joinable`
+//! trait, implemented for all `IntoIterator` types, which produces a lazy
+//! `Join` `Display` instance, as well as a small library of common
+//! zero-size separators.
+//!
+//! # Examples
+//!
+//! ```
+//! use joinery::Joinable;
+//!
+//! let parts = vec!["this", "is", "a", "sentence"];
+//! let join = parts.join_with(" ");
+//!
+//! assert_eq!(join.to_string(), "this is a sentence");
+//! ```
+
+#![no_std]
+
+extern crate std;
+
+pub mod join;
+pub mod separators;
+
+pub use join::{Join, Joinable};
+pub use separators::*;
@@ -6,6 +6,31 @@
 // type for empty-separator-joins.
 
 use core::fmt::{self, Display, Formatter};
+use std::string::String;
+use std::string::ToString;
+
+/// Implemented by separator types whose rendered length is known ahead of
+/// time, so that joins can pre-reserve capacity instead of growing an
+/// output buffer incrementally.
+///
+/// All separators in this module implement `Separator`. User-defined
+/// separators created with [`define_separator!`](crate::define_separator)
+/// do not implement it automatically, since the macro has no way to know
+/// the rendered length of an arbitrary `$sep` expression; implement it by
+/// hand if your separator's length is fixed.
+pub trait Separator: Display {
+    /// The byte length of this separator's `Display` rendering, if it is
+    /// known ahead of time. Returns `None` when the length can vary (for
+    /// example, a separator whose rendering depends on formatter flags).
+    fn len_hint(&self) -> Option<usize>;
+}
+
+impl Separator for NoSeparator {
+    #[inline]
+    fn len_hint(&self) -> Option<usize> {
+        Some(0)
+    }
+}
 
 /// Zero-size type representing the empty separator.
 ///
@@ -49,8 +74,25 @@ fn test_no_separator() {
     assert_eq!(result, "12345");
 }
 
-macro_rules! const_separator {
-    ($($Name:ident: $sep:expr => $test_name:ident,)+) => {$(
+/// Define a new zero-size separator type, with a `Display` impl that renders
+/// as the given literal. The resulting type is `Copy`, `Default`, and
+/// `#[must_use]`, exactly like the built-in separators in this module.
+///
+/// # Examples
+///
+/// ```
+/// use joinery::{define_separator, Joinable};
+///
+/// define_separator!(Pipe: "|");
+///
+/// let parts = [1, 2, 3];
+/// let join = parts.join_with(Pipe);
+/// assert_eq!(join.to_string(), "1|2|3");
+/// ```
+#[macro_export]
+macro_rules! define_separator {
+    ($(#[$attr:meta])* $Name:ident: $sep:expr) => {
+        $(#[$attr])*
         #[derive(Debug, Clone, Copy, Default)]
         #[must_use]
         pub struct $Name;
@@ -61,6 +103,19 @@ macro_rules! const_separator {
                 $sep.fmt(f)
             }
         }
+    };
+}
+
+macro_rules! const_separator {
+    ($($(#[$attr:meta])* $Name:ident: $sep:expr => $test_name:ident,)+) => {$(
+        define_separator!($(#[$attr])* $Name: $sep);
+
+        impl Separator for $Name {
+            #[inline]
+            fn len_hint(&self) -> Option<usize> {
+                Some($sep.len())
+            }
+        }
 
         #[cfg(test)]
         #[test]
@@ -85,4 +140,233 @@ const_separator! {
     Slash: "/" => test_slash,
     Underscore: "_" => test_underscore,
     Dash: "-" => test_dash,
+    Newline: "\n" => test_newline,
+    Tab: "\t" => test_tab,
+    Semicolon: ";" => test_semicolon,
+    Colon: ":" => test_colon,
+}
+
+/// Zero-size separator rendering as the platform's `PATH`-list separator:
+/// `;` on Windows, `:` everywhere else.
+///
+/// # Examples
+///
+/// ```
+/// use joinery::{Joinable, PathListSeparator};
+///
+/// let parts = ["/usr/bin", "/usr/local/bin"];
+/// let join = parts.join_with(PathListSeparator);
+///
+/// #[cfg(not(windows))]
+/// assert_eq!(join.to_string(), "/usr/bin:/usr/local/bin");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[must_use]
+pub struct PathListSeparator;
+
+impl Display for PathListSeparator {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if cfg!(windows) {
+            ';'.fmt(f)
+        } else {
+            ':'.fmt(f)
+        }
+    }
+}
+
+impl Separator for PathListSeparator {
+    #[inline]
+    fn len_hint(&self) -> Option<usize> {
+        // Both ';' and ':' are a single byte.
+        Some(1)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_path_list_separator() {
+    use crate::join::Joinable;
+    use crate::separators::PathListSeparator;
+
+    let data = ["a", "b", "c"];
+    let join = data.join_with(PathListSeparator);
+    let result = join.to_string();
+
+    let expected = if cfg!(windows) { "a;b;c" } else { "a:b:c" };
+    assert_eq!(result, expected);
+}
+
+#[cfg(test)]
+#[test]
+fn test_separator_len_hint() {
+    use crate::separators::{CommaSpace, NoSeparator, Separator};
+
+    assert_eq!(NoSeparator.len_hint(), Some(0));
+    assert_eq!(CommaSpace.len_hint(), Some(2));
+}
+
+/// Implemented by the primitive integer types, to extract a sign and a
+/// string of decimal digits for [`GroupDigits::group_digits`]. Its methods
+/// are hidden from the documentation; the trait itself is not sealed.
+pub trait Digits: Copy {
+    #[doc(hidden)]
+    fn is_negative(self) -> bool;
+    #[doc(hidden)]
+    fn unsigned_digits(self) -> String;
+}
+
+macro_rules! impl_signed_digits {
+    ($($Int:ty,)+) => {$(
+        impl Digits for $Int {
+            #[inline]
+            fn is_negative(self) -> bool {
+                self < 0
+            }
+
+            #[inline]
+            fn unsigned_digits(self) -> String {
+                self.unsigned_abs().to_string()
+            }
+        }
+    )+}
+}
+
+macro_rules! impl_unsigned_digits {
+    ($($Int:ty,)+) => {$(
+        impl Digits for $Int {
+            #[inline]
+            fn is_negative(self) -> bool {
+                false
+            }
+
+            #[inline]
+            fn unsigned_digits(self) -> String {
+                self.to_string()
+            }
+        }
+    )+}
+}
+
+impl_signed_digits!(i8, i16, i32, i64, i128, isize,);
+impl_unsigned_digits!(u8, u16, u32, u64, u128, usize,);
+
+/// The result of calling [`GroupDigits::group_digits`]. Lazily `Display`s
+/// an integer with `sep` inserted between groups of `group_size` digits,
+/// counting from the least significant digit.
+#[derive(Debug, Clone, Copy)]
+#[must_use]
+pub struct Grouped<T, S> {
+    value: T,
+    sep: S,
+    group_size: usize,
+}
+
+impl<T, S> Grouped<T, S> {
+    /// Use a group size other than the default of 3. Every group uses this
+    /// same size; mixed grouping such as 2-then-3 is not supported.
+    pub fn with_group_size(mut self, group_size: usize) -> Self {
+        self.group_size = group_size;
+        self
+    }
+}
+
+impl<T, S> Display for Grouped<T, S>
+where
+    T: Digits,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.value.is_negative() {
+            write!(f, "-")?;
+        }
+
+        let digits = self.value.unsigned_digits();
+        let len = digits.len();
+        let group_size = self.group_size.max(1);
+
+        let first_group_len = match len % group_size {
+            0 => group_size.min(len),
+            remainder => remainder,
+        };
+
+        write!(f, "{}", &digits[..first_group_len])?;
+
+        let mut start = first_group_len;
+        while start < len {
+            write!(f, "{}", self.sep)?;
+            write!(f, "{}", &digits[start..start + group_size])?;
+            start += group_size;
+        }
+
+        Ok(())
+    }
+}
+
+/// Extension trait adding [`group_digits`](GroupDigits::group_digits) to the
+/// primitive integer types, for rendering with a thousands-style separator.
+pub trait GroupDigits: Digits + Sized {
+    /// Group this integer's digits with `sep` inserted every 3 digits
+    /// (counting from the right), lazily, with no intermediate `Vec` of
+    /// groups.
+    ///
+    /// Use [`Grouped::with_group_size`] to change the group size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use joinery::{Comma, GroupDigits};
+    ///
+    /// let grouped = 1234567.group_digits(Comma);
+    /// assert_eq!(grouped.to_string(), "1,234,567");
+    ///
+    /// let negative = (-42).group_digits(Comma);
+    /// assert_eq!(negative.to_string(), "-42");
+    /// ```
+    fn group_digits<S>(self, sep: S) -> Grouped<Self, S> {
+        Grouped {
+            value: self,
+            sep,
+            group_size: 3,
+        }
+    }
+}
+
+impl<T: Digits> GroupDigits for T {}
+
+#[cfg(test)]
+mod group_digits_tests {
+    use crate::separators::{Comma, GroupDigits};
+    use std::string::ToString;
+
+    #[test]
+    fn test_group_digits() {
+        assert_eq!(1234567.group_digits(Comma).to_string(), "1,234,567");
+    }
+
+    #[test]
+    fn test_group_digits_short() {
+        assert_eq!(42.group_digits(Comma).to_string(), "42");
+    }
+
+    #[test]
+    fn test_group_digits_exact_group() {
+        assert_eq!(123456.group_digits(Comma).to_string(), "123,456");
+    }
+
+    #[test]
+    fn test_group_digits_negative() {
+        assert_eq!((-1234567).group_digits(Comma).to_string(), "-1,234,567");
+    }
+
+    #[test]
+    fn test_group_digits_unsigned() {
+        assert_eq!(1234567u32.group_digits(Comma).to_string(), "1,234,567");
+    }
+
+    #[test]
+    fn test_group_digits_custom_group_size() {
+        let grouped = 1234567.group_digits(Comma).with_group_size(2);
+        assert_eq!(grouped.to_string(), "1,23,45,67");
+    }
 }